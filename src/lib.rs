@@ -10,6 +10,155 @@
 /// is ill-defined, `None` will be returned.
 pub type StatFn = fn(&[f64]) -> Option<f64>;
 
+/// Ergonomic, method-based surface for the statistics in this crate.
+///
+/// Implemented for `[f64]`, so callers can write `data.mean()` instead
+/// of `mean(data)` for any `&[f64]` or `&Vec<f64>` (the latter via
+/// `Vec`'s `Deref<Target = [f64]>`, so no separate `impl` is needed).
+/// The free functions in this crate delegate to this trait so there is
+/// a single implementation of each statistic.
+pub trait Stats {
+    /// Sum of all values. The sum of an empty list is 0.0.
+    fn sum(&self) -> f64;
+
+    /// Smallest value, skipping any `NaN`s. Undefined for an empty list
+    /// or a list containing only `NaN`s.
+    fn min(&self) -> Option<f64>;
+
+    /// Largest value, skipping any `NaN`s. Undefined for an empty list
+    /// or a list containing only `NaN`s.
+    fn max(&self) -> Option<f64>;
+
+    /// Arithmetic mean. The mean of an empty list is 0.0.
+    fn mean(&self) -> Option<f64>;
+
+    /// Median value, taking the value closer to the beginning to break
+    /// ties, skipping any `NaN`s. Undefined for an empty list or a list
+    /// containing only `NaN`s.
+    fn median(&self) -> Option<f64>;
+
+    /// Population variance (mean of squared deviations from the mean).
+    /// Undefined for an empty list.
+    fn var(&self) -> Option<f64>;
+
+    /// Population standard deviation, the square root of `var`.
+    /// Undefined for an empty list.
+    fn std_dev(&self) -> Option<f64>;
+
+    /// Standard deviation expressed as a percentage of the mean:
+    /// `std_dev / mean.abs() * 100.0`. `None` when the mean is zero.
+    fn std_dev_pct(&self) -> Option<f64>;
+}
+
+impl Stats for [f64] {
+    fn sum(&self) -> f64 {
+        let mut sum: f64 = 0.0;
+        for num in self {
+            sum += num;
+        }
+        sum
+    }
+
+    fn min(&self) -> Option<f64> {
+        let mut min: Option<f64> = None;
+        for &num in self {
+            if num.is_nan() {
+                continue;
+            }
+            if min.is_none() || num < min.unwrap() {
+                min = Some(num);
+            }
+        }
+        min
+    }
+
+    fn max(&self) -> Option<f64> {
+        let mut max: Option<f64> = None;
+        for &num in self {
+            if num.is_nan() {
+                continue;
+            }
+            if max.is_none() || num > max.unwrap() {
+                max = Some(num);
+            }
+        }
+        max
+    }
+
+    fn mean(&self) -> Option<f64> {
+        // Empty list, mean is 0.0
+        if self.len() == 0 {
+            Some(0.0_f64)
+        } else {
+            Some(self.sum() / self.len() as f64)
+        }
+    }
+
+    fn median(&self) -> Option<f64> {
+        // Make a sorted copy of the input floats, skipping any `NaN`s
+        // (consistent with `min`/`max`/`range`, which are also
+        // NaN-aware): `NaN` has no ordering, so `partial_cmp` would
+        // otherwise fail to sort it.
+        let mut nums: Vec<f64> = Vec::with_capacity(self.len());
+        for &num in self {
+            if !num.is_nan() {
+                nums.push(num);
+            }
+        }
+        if nums.len() == 0 {
+            // Case |nums| = 0: return undefined
+            None
+        } else {
+            // https://users.rust-lang.org/t/how-to-sort-a-vec-of-floats/2838/2
+            nums.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            // For odd lengths this is the true middle element; for even
+            // lengths it's the lower of the two middle elements (ties
+            // broken toward the beginning of the slice).
+            let mid = (nums.len() - 1) / 2;
+            Some(nums[mid])
+        }
+    }
+
+    fn var(&self) -> Option<f64> {
+        if self.len() == 0 {
+            None
+        } else {
+            let mean_nums = self.mean().unwrap();
+            let mut sum: f64 = 0.0;
+            for num in self {
+                sum += (num - mean_nums).powi(2);
+            }
+            Some(sum / self.len() as f64)
+        }
+    }
+
+    fn std_dev(&self) -> Option<f64> {
+        self.var().map(|v| v.sqrt())
+    }
+
+    fn std_dev_pct(&self) -> Option<f64> {
+        match (self.std_dev(), self.mean()) {
+            (Some(sd), Some(m)) if m != 0.0 => Some(sd / m.abs() * 100.0),
+            _ => None,
+        }
+    }
+}
+
+// Added test
+#[test]
+fn test_stats_trait_added() {
+    let data = [3.5, 3.5, 3.5, 6.5, 6.5, 6.5];
+    assert_eq!(30.0, data.sum());
+    assert_eq!(Some(3.5), data.min());
+    assert_eq!(Some(6.5), data.max());
+    assert_eq!(Some(5.0), data.mean());
+    assert_eq!(Some(2.25), data.var());
+    assert_eq!(Some(1.5), data.std_dev());
+    assert_eq!(Some(30.0), data.std_dev_pct());
+    assert_eq!(None, Vec::<f64>::new().std_dev_pct());
+}
+
 /// Arithmetic mean of input values. The mean of an empty
 /// list is 0.0.
 ///
@@ -24,18 +173,7 @@ pub type StatFn = fn(&[f64]) -> Option<f64>;
 /// assert_eq!(Some(0.0), mean(&[-1.0, 1.0]));
 /// ```
 pub fn mean(nums: &[f64]) -> Option<f64> {
-    // Empty list, mean is 0.0
-    if nums.len() == 0 {
-        Some(0.0_f64)
-    } else {
-        // Non empty list, get sum of elements and divide by length of array
-        let mut sum: f64 = 0.0;
-        for num in nums {
-            sum += num;
-        }
-        sum = sum / nums.len() as f64;
-        Some(sum)
-    }
+    nums.mean()
 }
 
 // Added Test
@@ -58,20 +196,7 @@ fn test_mean_added() {
 /// assert_eq!(Some(0.0), stddev(&[1.0, 1.0]));
 /// ```
 pub fn stddev(nums: &[f64]) -> Option<f64> {
-    // Empty list, stddev is None
-    if nums.len() == 0 {
-        None
-    } else {
-        // Non empty list, Compute calculation similar to https://en.wikipedia.org/wiki/Standard_deviation#Population_standard_deviation_of_grades_of_eight_students
-        // Reference for Rust f64 https://doc.rust-lang.org/std/primitive.f64.html
-        let mean_nums = mean(nums).unwrap();
-        let mut sum: f64 = 0.0;
-        for num in nums {
-            sum += (num - mean_nums).powi(2);
-        }
-        sum = sum / nums.len() as f64;
-        Some(sum.sqrt())
-    }
+    nums.std_dev()
 }
 
 // Added test
@@ -81,8 +206,8 @@ fn test_stddev_added() {
 }
 
 /// Median value of input values, taking the value closer
-/// to the beginning to break ties. The median
-/// of an empty list is undefined.
+/// to the beginning to break ties, and skipping any `NaN`s. The median
+/// of an empty list, or a list containing only `NaN`s, is undefined.
 ///
 /// # Examples:
 ///
@@ -95,24 +220,20 @@ fn test_stddev_added() {
 /// assert_eq!(Some(0.0), median(&[0.0, 0.5, -1.0, 1.0]));
 /// ```
 pub fn median(nums: &[f64]) -> Option<f64> {
-    // Case |nums| = 0: return undefined
-    if nums.len() == 0 {
-        None
-    } else {
-        // Make a sorted copy of the input floats.
-        let mut nums = nums.to_owned();
-        // https://users.rust-lang.org/t/how-to-sort-a-vec-of-floats/2838/2
-        nums.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        let mid = ((nums.len() / 2) as f64).floor();
-        Some(nums[mid as usize - 1])
-    }
+    nums.median()
 }
 
 // Added test
 #[test]
 fn test_median_added() {
     assert_eq!(Some(-1.3), median(&[-1.7, 4.6, 0.0, -1.3, 9.5, -4.5]));
+    // Odd length: the true middle element, not the one before it.
+    assert_eq!(Some(3.0), median(&[5.0, 3.0, 1.0, 2.0, 4.0]));
+    // Single element: must not panic.
+    assert_eq!(Some(7.0), median(&[7.0]));
+    // NaN values are skipped rather than causing a panic.
+    assert_eq!(Some(2.0), median(&[1.0, f64::NAN, 2.0, 3.0]));
+    assert_eq!(None, median(&[f64::NAN, f64::NAN]));
 }
 
 /// L2 norm (Euclidean norm) of input values. The L2
@@ -144,3 +265,615 @@ pub fn l2(nums: &[f64]) -> Option<f64> {
         Some(sum.sqrt())
     }
 }
+
+/// Median absolute deviation (MAD) of input values: the median of the
+/// absolute deviations of each value from the overall median, scaled by
+/// `1.4826` so it is a consistent estimator of the standard deviation
+/// for normally-distributed data. Unlike `stddev`, this is resistant to
+/// outliers. `None` for an empty list, or a list containing only `NaN`s
+/// (since `median` is undefined in both cases).
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, median_abs_dev(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(0.0), median_abs_dev(&[1.0, 1.0, 1.0]));
+/// ```
+pub fn median_abs_dev(nums: &[f64]) -> Option<f64> {
+    // https://en.wikipedia.org/wiki/Median_absolute_deviation
+    let m = median(nums)?;
+    let mut deviations: Vec<f64> = Vec::with_capacity(nums.len());
+    for num in nums {
+        deviations.push((num - m).abs());
+    }
+    median(&deviations).map(|mdev| mdev * 1.4826)
+}
+
+// Added test
+#[test]
+fn test_median_abs_dev_added() {
+    assert_eq!(None, median_abs_dev(&[]));
+    assert_eq!(Some(0.0), median_abs_dev(&[1.0, 1.0, 1.0]));
+    // Single element: must not panic.
+    assert_eq!(Some(0.0), median_abs_dev(&[5.0]));
+    let mad = median_abs_dev(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).unwrap();
+    assert!((mad - 2.9652).abs() < 1e-9);
+    // NaN values are skipped rather than causing a panic.
+    let mad = median_abs_dev(&[1.0, f64::NAN, 2.0, 3.0]).unwrap();
+    assert!((mad - 1.4826).abs() < 1e-9);
+    assert_eq!(None, median_abs_dev(&[f64::NAN, f64::NAN]));
+}
+
+/// Median absolute deviation expressed as a percentage of the median:
+/// `median_abs_dev / median.abs() * 100.0`. `None` for an empty list or
+/// when the median is zero.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, median_abs_dev_pct(&[]));
+/// ```
+pub fn median_abs_dev_pct(nums: &[f64]) -> Option<f64> {
+    match (median_abs_dev(nums), median(nums)) {
+        (Some(mad), Some(m)) if m != 0.0 => Some(mad / m.abs() * 100.0),
+        _ => None,
+    }
+}
+
+// Added test
+#[test]
+fn test_median_abs_dev_pct_added() {
+    assert_eq!(None, median_abs_dev_pct(&[]));
+    let pct = median_abs_dev_pct(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]).unwrap();
+    assert!((pct - 74.13).abs() < 1e-2);
+    // NaN values are skipped rather than causing a panic.
+    assert_eq!(None, median_abs_dev_pct(&[f64::NAN, f64::NAN]));
+}
+
+/// Default outlier-rejection threshold used by `Histogram::new`.
+pub const DEFAULT_OUTLIER_THRESHOLD: f64 = 5.0;
+
+/// Rejects outliers from `nums` using a median-based rule: compute the
+/// median `m`, compute each point's absolute deviation `d_i = |x_i - m|`,
+/// then the median `mdev` of those deviations, and keep only points
+/// where `d_i / mdev < threshold`. If `mdev` is zero there is no spread
+/// to reject against, so all non-`NaN` points are kept. `NaN` values are
+/// never kept (their deviation and comparisons are always `NaN`/`false`), and
+/// an empty list, or a list containing only `NaN`s, rejects to empty.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(vec![1.0, 2.0, 3.0], reject_outliers(&[1.0, 2.0, 3.0, 1000.0], 5.0));
+/// ```
+pub fn reject_outliers(nums: &[f64], threshold: f64) -> Vec<f64> {
+    // Empty, or nothing but `NaN`s (`median` undefined either way): there
+    // is no reference point to reject outliers against.
+    let m = match median(nums) {
+        Some(m) => m,
+        None => return Vec::new(),
+    };
+    let mut deviations: Vec<f64> = Vec::with_capacity(nums.len());
+    for num in nums {
+        deviations.push((num - m).abs());
+    }
+    // `deviations` is non-empty here (since `nums` has at least one
+    // non-`NaN` value), so its median is always defined.
+    let mdev = median(&deviations).unwrap();
+
+    if mdev == 0.0 {
+        let mut kept: Vec<f64> = Vec::with_capacity(nums.len());
+        for &num in nums {
+            if !num.is_nan() {
+                kept.push(num);
+            }
+        }
+        return kept;
+    }
+
+    let mut kept: Vec<f64> = Vec::new();
+    for &num in nums {
+        if (num - m).abs() / mdev < threshold {
+            kept.push(num);
+        }
+    }
+    kept
+}
+
+// Added test
+#[test]
+fn test_reject_outliers_added() {
+    assert_eq!(
+        vec![1.0, 2.0, 3.0],
+        reject_outliers(&[1.0, 2.0, 3.0, 1000.0], 5.0)
+    );
+    assert_eq!(
+        vec![1.0, 1.0, 1.0],
+        reject_outliers(&[1.0, 1.0, 1.0], 5.0)
+    );
+    // NaN values are dropped rather than causing a panic.
+    assert_eq!(
+        vec![1.0, 2.0, 3.0],
+        reject_outliers(&[1.0, 2.0, f64::NAN, 3.0], 5.0)
+    );
+    assert_eq!(Vec::<f64>::new(), reject_outliers(&[f64::NAN, f64::NAN], 5.0));
+}
+
+/// Finds the index of the bin in `boundaries` (a `bin_count + 1` length
+/// list of equally-spaced edges) that `value` falls into, treating bins
+/// as `[lower, upper)` except for the last bin, which also accepts its
+/// upper boundary. Returns `None` if `value` is outside the range.
+fn bin_index(boundaries: &[f64], value: f64) -> Option<usize> {
+    if boundaries.len() < 2 {
+        return None;
+    }
+
+    let bin_count = boundaries.len() - 1;
+    if value < boundaries[0] || value > boundaries[bin_count] {
+        return None;
+    }
+
+    for i in 0..bin_count {
+        let is_last = i == bin_count - 1;
+        if value >= boundaries[i] && (value < boundaries[i + 1] || is_last) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// A fixed-bin-count histogram of a slice of values.
+///
+/// `Histogram::new` first rejects outliers (see `reject_outliers`, using
+/// `DEFAULT_OUTLIER_THRESHOLD`), then spans the min/max of the surviving
+/// data with `bin_count` equal-width bins.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// let h = Histogram::new(&[1.0, 2.0, 3.0, 4.0], 2);
+/// assert_eq!(&[2, 2], h.bins());
+/// assert_eq!(&[1.0, 2.5, 4.0], h.boundaries());
+/// ```
+pub struct Histogram {
+    bins: Vec<usize>,
+    boundaries: Vec<f64>,
+}
+
+impl Histogram {
+    /// Builds a histogram of `data` with `bin_count` equal-width bins,
+    /// after rejecting outliers with `DEFAULT_OUTLIER_THRESHOLD`.
+    pub fn new(data: &[f64], bin_count: usize) -> Histogram {
+        let data = reject_outliers(data, DEFAULT_OUTLIER_THRESHOLD);
+
+        if data.len() == 0 || bin_count == 0 {
+            return Histogram {
+                bins: Vec::new(),
+                boundaries: Vec::new(),
+            };
+        }
+
+        let lo = data.min().unwrap();
+        let hi = data.max().unwrap();
+
+        // All surviving values are equal: collapse to a single bin
+        // rather than dividing a zero-width range.
+        if lo == hi {
+            return Histogram {
+                bins: vec![data.len()],
+                boundaries: vec![lo, hi],
+            };
+        }
+
+        let width = (hi - lo) / bin_count as f64;
+        let mut boundaries: Vec<f64> = Vec::with_capacity(bin_count + 1);
+        for i in 0..=bin_count {
+            boundaries.push(lo + width * i as f64);
+        }
+
+        let mut bins = vec![0; bin_count];
+        for &value in &data {
+            if let Some(bin) = bin_index(&boundaries, value) {
+                bins[bin] += 1;
+            }
+        }
+
+        Histogram { bins, boundaries }
+    }
+
+    /// Counts of values falling into each bin, in order.
+    pub fn bins(&self) -> &[usize] {
+        &self.bins
+    }
+
+    /// The `bin_count + 1` boundary values spanning the (post-rejection)
+    /// range of the data. When all surviving values are equal there is a
+    /// single bin and two (equal) boundaries; when no data survives, or
+    /// `bin_count` is zero, there are no bins and no boundaries.
+    pub fn boundaries(&self) -> &[f64] {
+        &self.boundaries
+    }
+
+    /// Lower boundary of the bin `value` falls into, or `None` if
+    /// `value` is outside the histogram's range.
+    pub fn to_bin(&self, value: f64) -> Option<f64> {
+        bin_index(&self.boundaries, value).map(|bin| self.boundaries[bin])
+    }
+}
+
+// Added test
+#[test]
+fn test_histogram_added() {
+    let h = Histogram::new(&[1.0, 2.0, 3.0, 4.0], 2);
+    assert_eq!(&[2, 2], h.bins());
+    assert_eq!(&[1.0, 2.5, 4.0], h.boundaries());
+    assert_eq!(Some(1.0), h.to_bin(1.5));
+    assert_eq!(Some(2.5), h.to_bin(4.0));
+    assert_eq!(None, h.to_bin(5.0));
+
+    let h = Histogram::new(&[], 4);
+    assert_eq!(0, h.bins().len());
+    assert_eq!(0, h.boundaries().len());
+
+    let h = Histogram::new(&[3.0, 3.0, 3.0], 4);
+    assert_eq!(&[3], h.bins());
+    assert_eq!(&[3.0, 3.0], h.boundaries());
+
+    // Single value: must not panic, and collapses to one bin.
+    let h = Histogram::new(&[5.0], 3);
+    assert_eq!(&[1], h.bins());
+    assert_eq!(&[5.0, 5.0], h.boundaries());
+
+    // Odd-length, distinct-value input: exercises the corrected median
+    // used by outlier rejection.
+    let h = Histogram::new(&[1.0, 2.0, 3.0], 2);
+    assert_eq!(&[1, 2], h.bins());
+    assert_eq!(&[1.0, 2.0, 3.0], h.boundaries());
+
+    // NaN values must not panic; they are dropped during outlier
+    // rejection, since a NaN's deviation never satisfies the
+    // rejection-threshold comparison.
+    let h = Histogram::new(&[1.0, 2.0, f64::NAN, 3.0], 2);
+    assert_eq!(&[1, 2], h.bins());
+    assert_eq!(&[1.0, 2.0, 3.0], h.boundaries());
+}
+
+/// Geometric mean of input values: the n-th root of the product of all
+/// values, computed in log-space as `exp(mean(ln(x_i)))` to avoid
+/// overflow. `None` for an empty list, or if any value is not strictly
+/// positive (the geometric mean is undefined for zero or negative
+/// values).
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, geometric_mean(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, geometric_mean(&[1.0, -4.0]));
+/// ```
+pub fn geometric_mean(nums: &[f64]) -> Option<f64> {
+    if nums.len() == 0 {
+        return None;
+    }
+
+    let mut sum_ln: f64 = 0.0;
+    for num in nums {
+        if *num <= 0.0 {
+            return None;
+        }
+        sum_ln += num.ln();
+    }
+    Some((sum_ln / nums.len() as f64).exp())
+}
+
+// Added test
+#[test]
+fn test_geometric_mean_added() {
+    assert_eq!(None, geometric_mean(&[]));
+    assert_eq!(None, geometric_mean(&[1.0, 0.0]));
+    assert_eq!(None, geometric_mean(&[1.0, -4.0]));
+    assert!((geometric_mean(&[1.0, 4.0]).unwrap() - 2.0).abs() < 1e-9);
+}
+
+/// Harmonic mean of input values: `n / sum(1 / x_i)`. `None` for an
+/// empty list, or if any value is zero.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, harmonic_mean(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, harmonic_mean(&[1.0, 0.0]));
+/// ```
+pub fn harmonic_mean(nums: &[f64]) -> Option<f64> {
+    if nums.len() == 0 {
+        return None;
+    }
+
+    let mut sum_recip: f64 = 0.0;
+    for num in nums {
+        if *num == 0.0 {
+            return None;
+        }
+        sum_recip += 1.0 / num;
+    }
+    Some(nums.len() as f64 / sum_recip)
+}
+
+// Added test
+#[test]
+fn test_harmonic_mean_added() {
+    assert_eq!(None, harmonic_mean(&[]));
+    assert_eq!(None, harmonic_mean(&[1.0, 0.0]));
+    assert_eq!(Some(2.0), harmonic_mean(&[2.0, 2.0, 2.0]));
+}
+
+/// Root-mean-square of input values: `sqrt(mean(x_i^2))`. `None` for an
+/// empty list.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, rms(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(2.0), rms(&[2.0, 2.0]));
+/// ```
+pub fn rms(nums: &[f64]) -> Option<f64> {
+    if nums.len() == 0 {
+        None
+    } else {
+        let mut sum: f64 = 0.0;
+        for num in nums {
+            sum += num.powi(2);
+        }
+        Some((sum / nums.len() as f64).sqrt())
+    }
+}
+
+// Added test
+#[test]
+fn test_rms_added() {
+    assert_eq!(None, rms(&[]));
+    assert!((rms(&[3.0, 4.0]).unwrap() - 3.5355339059327378).abs() < 1e-9);
+}
+
+/// Number of occurrences of `val` in `nums`, compared by exact equality
+/// (via `==`, with no tolerance; `0.0` and `-0.0` count as equal). Note
+/// that, as with any `f64` comparison, `NaN`
+/// never compares equal to itself, so `freq` of a `NaN` value is always
+/// `0`. `0` for an empty list.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(0, freq(&[], 1.0));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(2, freq(&[1.0, 2.0, 1.0], 1.0));
+/// ```
+pub fn freq(nums: &[f64], val: f64) -> usize {
+    let mut count = 0;
+    for num in nums {
+        if *num == val {
+            count += 1;
+        }
+    }
+    count
+}
+
+// Added test
+#[test]
+fn test_freq_added() {
+    assert_eq!(0, freq(&[], 1.0));
+    assert_eq!(2, freq(&[1.0, 2.0, 1.0], 1.0));
+    assert_eq!(0, freq(&[1.0, 2.0, 1.0], 3.0));
+}
+
+/// Most frequently occurring value, breaking ties toward the value
+/// nearer the start of the slice (matching the tie-breaking philosophy
+/// of `median`). Undefined for an empty list.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, mode(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(1.0), mode(&[1.0, 2.0, 1.0]));
+/// ```
+pub fn mode(nums: &[f64]) -> Option<f64> {
+    if nums.len() == 0 {
+        return None;
+    }
+
+    let mut best = nums[0];
+    let mut best_count = 0;
+    for &num in nums {
+        let count = freq(nums, num);
+        if count > best_count {
+            best_count = count;
+            best = num;
+        }
+    }
+    Some(best)
+}
+
+// Added test
+#[test]
+fn test_mode_added() {
+    assert_eq!(None, mode(&[]));
+    assert_eq!(Some(1.0), mode(&[1.0, 2.0, 1.0]));
+    // Tie between 1.0 and 2.0: the one appearing first wins.
+    assert_eq!(Some(1.0), mode(&[1.0, 2.0, 1.0, 2.0]));
+}
+
+/// Population variance of input values (mean of squared deviations from
+/// the mean), named as a companion to `stddev`. An alias for
+/// `Stats::var`. Undefined for an empty list.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, population_variance(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(2.25), population_variance(&[3.5, 3.5, 3.5, 6.5, 6.5, 6.5]));
+/// ```
+pub fn population_variance(nums: &[f64]) -> Option<f64> {
+    nums.var()
+}
+
+/// Sample variance of input values: the sum of squared deviations from
+/// the mean divided by `n - 1` (Bessel's correction), the unbiased
+/// estimator to use when `nums` is a sample rather than a whole
+/// population. `None` for fewer than two values, since `n - 1 == 0` is
+/// undefined.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, sample_variance(&[1.0]));
+/// ```
+pub fn sample_variance(nums: &[f64]) -> Option<f64> {
+    if nums.len() < 2 {
+        return None;
+    }
+
+    let m = mean(nums).unwrap();
+    let mut sum: f64 = 0.0;
+    for num in nums {
+        sum += (num - m).powi(2);
+    }
+    Some(sum / (nums.len() - 1) as f64)
+}
+
+// Added test
+#[test]
+fn test_sample_variance_added() {
+    assert_eq!(None, sample_variance(&[]));
+    assert_eq!(None, sample_variance(&[1.0]));
+    let var = sample_variance(&[3.5, 3.5, 3.5, 6.5, 6.5, 6.5]).unwrap();
+    assert!((var - 2.7).abs() < 1e-9);
+}
+
+/// Sample standard deviation, the square root of `sample_variance`.
+/// `None` for fewer than two values.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, sample_stddev(&[1.0]));
+/// ```
+pub fn sample_stddev(nums: &[f64]) -> Option<f64> {
+    sample_variance(nums).map(|v| v.sqrt())
+}
+
+// Added test
+#[test]
+fn test_sample_stddev_added() {
+    assert_eq!(None, sample_stddev(&[1.0]));
+    let sd = sample_stddev(&[3.5, 3.5, 3.5, 6.5, 6.5, 6.5]).unwrap();
+    assert!((sd - 1.643_167_672_515_498).abs() < 1e-9);
+}
+
+/// Smallest value in `nums`, skipping any `NaN`s (see `Stats::min`).
+/// `None` if `nums` is empty or contains only `NaN`s.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, min(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(-4.5), min(&[-1.7, 4.6, -4.5]));
+/// ```
+pub fn min(nums: &[f64]) -> Option<f64> {
+    nums.min()
+}
+
+// Added test
+#[test]
+fn test_min_added() {
+    assert_eq!(None, min(&[]));
+    assert_eq!(Some(-4.5), min(&[-1.7, 4.6, -4.5]));
+    assert_eq!(Some(-1.7), min(&[-1.7, f64::NAN, 4.6]));
+}
+
+/// Largest value in `nums`, skipping any `NaN`s (see `Stats::max`).
+/// `None` if `nums` is empty or contains only `NaN`s.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, max(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(4.6), max(&[-1.7, 4.6, -4.5]));
+/// ```
+pub fn max(nums: &[f64]) -> Option<f64> {
+    nums.max()
+}
+
+// Added test
+#[test]
+fn test_max_added() {
+    assert_eq!(None, max(&[]));
+    assert_eq!(Some(4.6), max(&[-1.7, 4.6, -4.5]));
+    assert_eq!(Some(4.6), max(&[-1.7, f64::NAN, 4.6]));
+}
+
+/// Range of `nums`, i.e. `max - min`, skipping any `NaN`s. `None` if
+/// `nums` is empty or contains only `NaN`s.
+///
+/// # Examples:
+///
+/// ```
+/// # use stats::*;
+/// assert_eq!(None, range(&[]));
+/// ```
+/// ```
+/// # use stats::*;
+/// assert_eq!(Some(9.1), range(&[-4.5, -1.7, 4.6]));
+/// ```
+pub fn range(nums: &[f64]) -> Option<f64> {
+    match (max(nums), min(nums)) {
+        (Some(hi), Some(lo)) => Some(hi - lo),
+        _ => None,
+    }
+}
+
+// Added test
+#[test]
+fn test_range_added() {
+    assert_eq!(None, range(&[]));
+    let r = range(&[-4.5, -1.7, 4.6]).unwrap();
+    assert!((r - 9.1).abs() < 1e-9);
+}